@@ -0,0 +1,48 @@
+//! Throughput benchmarks for the word-parallel keystream path. Run with
+//! `cargo bench` (requires the `criterion` dev-dependency).
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use grain128::Grain128;
+
+const KEY: [u8; 16] = [0x42; 16];
+const IV: [u8; 12] = [0x11; 12];
+
+fn bench_keystream_bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("keystream_bytes");
+
+    for size in [64usize, 1024, 1 << 16] {
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_function(format!("{size}_bytes"), |b| {
+            let mut buf = vec![0u8; size];
+            b.iter(|| {
+                let mut g = Grain128::keysetup(&KEY, 128, 96);
+                g.ivsetup(&IV);
+                g.keystream_bytes(black_box(&mut buf));
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_encrypt_bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encrypt_bytes");
+
+    for size in [64usize, 1024, 1 << 16] {
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_function(format!("{size}_bytes"), |b| {
+            let plaintext = vec![0u8; size];
+            let mut ciphertext = vec![0u8; size];
+            b.iter(|| {
+                let mut g = Grain128::keysetup(&KEY, 128, 96);
+                g.ivsetup(&IV);
+                g.encrypt_bytes(black_box(&plaintext), black_box(&mut ciphertext));
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_keystream_bytes, bench_encrypt_bytes);
+criterion_main!(benches);
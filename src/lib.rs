@@ -1,14 +1,126 @@
 use std::convert::TryInto;
 
+#[cfg(feature = "cipher")]
+mod rc_cipher;
+#[cfg(feature = "cipher")]
+pub use rc_cipher::Grain128Cipher;
+
+pub mod io;
+
+/// Length in bytes of the Grain-128a MAC tag produced by [`Grain128::encrypt_with_tag`].
+pub const TAG_LEN: usize = 4;
+
+/// Returned by [`Grain128::decrypt_verify`] when the supplied tag does not match
+/// the tag recomputed over the ciphertext.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagMismatch;
+
+impl std::fmt::Display for TagMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "grain128: authentication tag mismatch")
+    }
+}
+
+impl std::error::Error for TagMismatch {}
+
+/// Errors returned by the fallible, attacker-input-safe API ([`encrypt`],
+/// [`decrypt`], [`Grain128::try_encrypt_bytes`], [`Grain128::try_decrypt_bytes`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The key was not 16 bytes long.
+    InvalidKeyLength,
+    /// The IV was neither 12 nor 16 bytes long.
+    InvalidIvLength,
+    /// The input and output buffers passed to a byte-oriented method had
+    /// different lengths.
+    BufferSizeMismatch,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidKeyLength => write!(f, "grain128: key must be 16 bytes"),
+            Error::InvalidIvLength => write!(f, "grain128: IV must be 12 or 16 bytes"),
+            Error::BufferSizeMismatch => {
+                write!(f, "grain128: input and output buffers have different lengths")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Validates `key`/`iv` lengths and returns the IV length in bits (96 or 128)
+/// that [`Grain128::keysetup`] expects for that IV.
+fn validate_key_iv(key: &[u8], iv: &[u8]) -> Result<usize, Error> {
+    if key.len() != 16 {
+        return Err(Error::InvalidKeyLength);
+    }
+
+    match iv.len() {
+        12 => Ok(96),
+        16 => Ok(128),
+        _ => Err(Error::InvalidIvLength),
+    }
+}
+
+/// One-shot encryption: validates `key`/`iv` lengths and returns the
+/// encrypted `plaintext`, never panicking on attacker-controlled input.
+pub fn encrypt(key: &[u8], iv: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+    let ivsize = validate_key_iv(key, iv)?;
+    let mut g = Grain128::keysetup(key, 128, ivsize);
+    g.ivsetup(iv);
+
+    let mut ciphertext = vec![0u8; plaintext.len()];
+    g.try_encrypt_bytes(plaintext, &mut ciphertext)?;
+    Ok(ciphertext)
+}
+
+/// One-shot decryption: validates `key`/`iv` lengths and returns the
+/// decrypted `ciphertext`, never panicking on attacker-controlled input.
+pub fn decrypt(key: &[u8], iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+    let ivsize = validate_key_iv(key, iv)?;
+    let mut g = Grain128::keysetup(key, 128, ivsize);
+    g.ivsetup(iv);
+
+    let mut plaintext = vec![0u8; ciphertext.len()];
+    g.try_decrypt_bytes(ciphertext, &mut plaintext)?;
+    Ok(plaintext)
+}
+
+/// Compares two equal-length byte slices in constant time.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+/// `LFSR`/`NFSR` are packed one bit per register position into a `u128`, bit
+/// `i` holding what used to be `register[i]`. The highest tap read by
+/// [`Grain128::keystream`]'s feedback/output functions is 96 and the
+/// register is 128 bits wide, so `128 - 96 = 32` freshly shifted-in bits are
+/// never read back by a tap before a 32-bit block of them is complete. That
+/// lets [`Grain128::keystream_block32`] compute a whole block from the
+/// pre-block state with plain word-level shifts/XOR/AND instead of
+/// one-bit-at-a-time array shuffling.
 #[derive(Clone)]
 #[allow(non_snake_case)]
 pub struct Grain128 {
-    LFSR: [u8; 128],
-    NFSR: [u8; 128],
+    LFSR: u128,
+    NFSR: u128,
 
     key: [u8; 16],
     keysize: usize,
     ivsize: usize,
+
+    /// Whether this instance runs the Grain-128a authenticated mode.
+    authenticate: bool,
 }
 
 impl Grain128 {
@@ -17,8 +129,18 @@ impl Grain128 {
             key: key.try_into().unwrap(),
             keysize,
             ivsize,
-            LFSR: [0u8; 128],
-            NFSR: [0u8; 128],
+            LFSR: 0,
+            NFSR: 0,
+            authenticate: false,
+        }
+    }
+
+    /// Like [`Grain128::keysetup`], but enables the Grain-128a authenticated mode
+    /// so [`Grain128::encrypt_with_tag`] and [`Grain128::decrypt_verify`] can be used.
+    pub fn keysetup_authenticated(key: &[u8], keysize: usize, ivsize: usize) -> Self {
+        Self {
+            authenticate: true,
+            ..Self::keysetup(key, keysize, ivsize)
         }
     }
 
@@ -43,73 +165,125 @@ impl Grain128 {
     pub fn ivsetup(&mut self, iv: &[u8]) {
         for i in 0..(self.ivsize / 8) {
             for j in 0..8 {
-                self.NFSR[i * 8 + j] = (self.key[i] >> j) & 1;
-                self.LFSR[i * 8 + j] = (iv[i] >> j) & 1;
+                self.NFSR |= (((self.key[i] >> j) & 1) as u128) << (i * 8 + j);
+                self.LFSR |= (((iv[i] >> j) & 1) as u128) << (i * 8 + j);
             }
         }
 
         for i in self.ivsize / 8..self.keysize / 8 {
             for j in 0..8 {
-                self.NFSR[i * 8 + j] = (self.key[i] >> j) & 1;
-                self.LFSR[i * 8 + j] = 1;
+                self.NFSR |= (((self.key[i] >> j) & 1) as u128) << (i * 8 + j);
+                self.LFSR |= 1u128 << (i * 8 + j);
             }
         }
 
         /* do initial clockings */
         for _ in 0..256 {
             let outbit = self.keystream();
-            self.LFSR[127] ^= outbit;
-            self.NFSR[127] ^= outbit;
+            self.LFSR ^= (outbit as u128) << 127;
+            self.NFSR ^= (outbit as u128) << 127;
         }
     }
 
+    /// Reads bits `tap..tap+31` of `reg` (bit `tap` in bit position 0 of the
+    /// result), i.e. the 32-bit window that a tap at index `tap` would see
+    /// across 32 consecutive single-bit clockings.
+    #[inline]
+    fn window32(reg: u128, tap: u32) -> u32 {
+        (reg >> tap) as u32
+    }
+
     /// Generates a new bit and updates the internal state of the cipher.
     fn keystream(&mut self) -> u8 {
+        let nfsr = self.NFSR;
+        let lfsr = self.LFSR;
+        let n = |tap: u32| ((nfsr >> tap) & 1) as u8;
+        let l = |tap: u32| ((lfsr >> tap) & 1) as u8;
+
         /* Calculate feedback and output bits */
-        let outbit = self.NFSR[2]
-            ^ self.NFSR[15]
-            ^ self.NFSR[36]
-            ^ self.NFSR[45]
-            ^ self.NFSR[64]
-            ^ self.NFSR[73]
-            ^ self.NFSR[89]
-            ^ self.LFSR[93]
-            ^ (self.NFSR[12] & self.LFSR[8])
-            ^ (self.LFSR[13] & self.LFSR[20])
-            ^ (self.NFSR[95] & self.LFSR[42])
-            ^ (self.LFSR[60] & self.LFSR[79])
-            ^ (self.NFSR[12] & self.NFSR[95] & self.LFSR[95]);
-
-        let n_bit = self.LFSR[0]
-            ^ self.NFSR[0]
-            ^ self.NFSR[26]
-            ^ self.NFSR[56]
-            ^ self.NFSR[91]
-            ^ self.NFSR[96]
-            ^ (self.NFSR[3] & self.NFSR[67])
-            ^ (self.NFSR[11] & self.NFSR[13])
-            ^ (self.NFSR[17] & self.NFSR[18])
-            ^ (self.NFSR[27] & self.NFSR[59])
-            ^ (self.NFSR[40] & self.NFSR[48])
-            ^ (self.NFSR[61] & self.NFSR[65])
-            ^ (self.NFSR[68] & self.NFSR[84]);
-
-        let l_bit = self.LFSR[0]
-            ^ self.LFSR[7]
-            ^ self.LFSR[38]
-            ^ self.LFSR[70]
-            ^ self.LFSR[81]
-            ^ self.LFSR[96];
+        let outbit = n(2)
+            ^ n(15)
+            ^ n(36)
+            ^ n(45)
+            ^ n(64)
+            ^ n(73)
+            ^ n(89)
+            ^ l(93)
+            ^ (n(12) & l(8))
+            ^ (l(13) & l(20))
+            ^ (n(95) & l(42))
+            ^ (l(60) & l(79))
+            ^ (n(12) & n(95) & l(95));
+
+        let n_bit = l(0)
+            ^ n(0)
+            ^ n(26)
+            ^ n(56)
+            ^ n(91)
+            ^ n(96)
+            ^ (n(3) & n(67))
+            ^ (n(11) & n(13))
+            ^ (n(17) & n(18))
+            ^ (n(27) & n(59))
+            ^ (n(40) & n(48))
+            ^ (n(61) & n(65))
+            ^ (n(68) & n(84));
+
+        let l_bit = l(0) ^ l(7) ^ l(38) ^ l(70) ^ l(81) ^ l(96);
 
         /* Update registers */
-        for i in 1..self.keysize {
-            self.NFSR[i - 1] = self.NFSR[i];
-            self.LFSR[i - 1] = self.LFSR[i];
-        }
+        self.NFSR = (nfsr >> 1) | ((n_bit as u128) << 127);
+        self.LFSR = (lfsr >> 1) | ((l_bit as u128) << 127);
+
+        outbit
+    }
+
+    /// Generates 32 keystream bits at once and advances the registers by 32
+    /// positions, per the word-parallel construction documented on
+    /// [`Grain128`]. Bit `j` of the result is the bit that the `(j+1)`-th of 32
+    /// consecutive calls to the single-bit clocking would have produced.
+    fn keystream_block32(&mut self) -> u32 {
+        let nfsr = self.NFSR;
+        let lfsr = self.LFSR;
+        let n = |tap: u32| Self::window32(nfsr, tap);
+        let l = |tap: u32| Self::window32(lfsr, tap);
+
+        /* Calculate feedback and output bits, 32 at a time */
+        let outbits = n(2)
+            ^ n(15)
+            ^ n(36)
+            ^ n(45)
+            ^ n(64)
+            ^ n(73)
+            ^ n(89)
+            ^ l(93)
+            ^ (n(12) & l(8))
+            ^ (l(13) & l(20))
+            ^ (n(95) & l(42))
+            ^ (l(60) & l(79))
+            ^ (n(12) & n(95) & l(95));
+
+        let n_bits = l(0)
+            ^ n(0)
+            ^ n(26)
+            ^ n(56)
+            ^ n(91)
+            ^ n(96)
+            ^ (n(3) & n(67))
+            ^ (n(11) & n(13))
+            ^ (n(17) & n(18))
+            ^ (n(27) & n(59))
+            ^ (n(40) & n(48))
+            ^ (n(61) & n(65))
+            ^ (n(68) & n(84));
+
+        let l_bits = l(0) ^ l(7) ^ l(38) ^ l(70) ^ l(81) ^ l(96);
 
-        self.NFSR[(self.keysize) - 1] = n_bit;
-        self.LFSR[(self.keysize) - 1] = l_bit;
-        return outbit;
+        /* Shift in the 32 freshly computed feedback bits as one word op */
+        self.NFSR = (nfsr >> 32) | ((n_bits as u128) << 96);
+        self.LFSR = (lfsr >> 32) | ((l_bits as u128) << 96);
+
+        outbits
     }
 
     /// Generate keystream in bytes
@@ -128,34 +302,235 @@ impl Grain128 {
     /// * ...
     /// * ...
     pub fn keystream_bytes(&mut self, keystream: &mut [u8]) {
-        for i in 0..keystream.len() {
-            keystream[i] = 0;
-
-            for j in 0..8 {
-                keystream[i] |= self.keystream() << j;
-            }
+        let mut i = 0;
+        while i + 4 <= keystream.len() {
+            keystream[i..i + 4].copy_from_slice(&self.keystream_block32().to_le_bytes());
+            i += 4;
+        }
+        while i < keystream.len() {
+            keystream[i] = self.keystream_byte();
+            i += 1;
         }
     }
 
     pub fn encrypt_bytes(&mut self, plaintext: &[u8], ciphertext: &mut [u8]) {
-        for i in 0..plaintext.len() {
-            let mut k = 0;
+        let mut i = 0;
+        while i + 4 <= plaintext.len() {
+            let p = u32::from_le_bytes(plaintext[i..i + 4].try_into().unwrap());
+            ciphertext[i..i + 4].copy_from_slice(&(p ^ self.keystream_block32()).to_le_bytes());
+            i += 4;
+        }
+        while i < plaintext.len() {
+            ciphertext[i] = plaintext[i] ^ self.keystream_byte();
+            i += 1;
+        }
+    }
 
-            for j in 0..8 {
-                k |= self.keystream() << j;
+    pub fn decrypt_bytes(&mut self, ciphertext: &[u8], plaintext: &mut [u8]) {
+        let mut i = 0;
+        while i + 4 <= ciphertext.len() {
+            let c = u32::from_le_bytes(ciphertext[i..i + 4].try_into().unwrap());
+            plaintext[i..i + 4].copy_from_slice(&(c ^ self.keystream_block32()).to_le_bytes());
+            i += 4;
+        }
+        while i < ciphertext.len() {
+            plaintext[i] = ciphertext[i] ^ self.keystream_byte();
+            i += 1;
+        }
+    }
+
+    /// Like [`Grain128::encrypt_bytes`], but returns [`Error::BufferSizeMismatch`]
+    /// instead of panicking if `ciphertext` isn't the same length as `plaintext`.
+    pub fn try_encrypt_bytes(
+        &mut self,
+        plaintext: &[u8],
+        ciphertext: &mut [u8],
+    ) -> Result<(), Error> {
+        if plaintext.len() != ciphertext.len() {
+            return Err(Error::BufferSizeMismatch);
+        }
+        self.encrypt_bytes(plaintext, ciphertext);
+        Ok(())
+    }
+
+    /// Like [`Grain128::decrypt_bytes`], but returns [`Error::BufferSizeMismatch`]
+    /// instead of panicking if `plaintext` isn't the same length as `ciphertext`.
+    pub fn try_decrypt_bytes(
+        &mut self,
+        ciphertext: &[u8],
+        plaintext: &mut [u8],
+    ) -> Result<(), Error> {
+        if ciphertext.len() != plaintext.len() {
+            return Err(Error::BufferSizeMismatch);
+        }
+        self.decrypt_bytes(ciphertext, plaintext);
+        Ok(())
+    }
+
+    /// XORs the keystream into `buf` in place. Encryption and decryption are
+    /// the same operation since XOR is its own inverse; used by
+    /// [`crate::io::Grain128Reader`]/[`crate::io::Grain128Writer`], where the
+    /// in/out buffer is one and the same.
+    pub fn apply_keystream(&mut self, buf: &mut [u8]) {
+        let mut i = 0;
+        while i + 4 <= buf.len() {
+            let chunk = u32::from_le_bytes(buf[i..i + 4].try_into().unwrap());
+            buf[i..i + 4].copy_from_slice(&(chunk ^ self.keystream_block32()).to_le_bytes());
+            i += 4;
+        }
+        while i < buf.len() {
+            buf[i] ^= self.keystream_byte();
+            i += 1;
+        }
+    }
+
+    /// Generates a single keystream byte, lsb-first (`bit 0` of the byte is
+    /// the earliest generated bit), by packing 8 calls to [`Grain128::keystream`].
+    pub(crate) fn keystream_byte(&mut self) -> u8 {
+        let mut byte = 0u8;
+        for j in 0..8 {
+            byte |= self.keystream() << j;
+        }
+        byte
+    }
+
+    /// Derives the initial 32-bit accumulator `a` and shift register `r` from the
+    /// first 64 pre-output bits of the authenticated stream (`a_j = z_j`,
+    /// `r_j = z_{32+j}`, `j = 0..31`).
+    fn mac_init(&mut self) -> (u32, u32) {
+        let mut a = 0u32;
+        for j in 0..32 {
+            a |= (self.keystream() as u32) << j;
+        }
+
+        let mut r = 0u32;
+        for j in 0..32 {
+            r |= (self.keystream() as u32) << j;
+        }
+
+        (a, r)
+    }
+
+    /// Runs the Grain-128a MAC over one message bit, updating `a` and `r` in place.
+    ///
+    /// `auth_bit` is the next MAC stream bit (`z_{2i+1}`), and `m_bit` is the
+    /// corresponding plaintext bit (or the padding bit `1` once the message is
+    /// exhausted). The encryption keystream bit `z_{2i}` is applied by the caller.
+    fn mac_step(a: &mut u32, r: &mut u32, auth_bit: u8, m_bit: u8) {
+        if m_bit == 1 {
+            *a ^= *r;
+        }
+        *r = (*r >> 1) | ((auth_bit as u32) << 31);
+    }
+
+    /// Encrypts `plaintext` into `ciphertext` and produces a Grain-128a MAC tag
+    /// covering it, per the accumulator/shift-register construction described in
+    /// the Grain-128a specification. Requires an instance created with
+    /// [`Grain128::keysetup_authenticated`].
+    pub fn encrypt_with_tag(
+        &mut self,
+        plaintext: &[u8],
+        ciphertext: &mut [u8],
+        tag: &mut [u8; TAG_LEN],
+    ) {
+        assert!(
+            self.authenticate,
+            "encrypt_with_tag requires Grain128::keysetup_authenticated"
+        );
+
+        let (mut a, mut r) = self.mac_init();
+        let bit_len = plaintext.len() * 8;
+        let mut byte = 0u8;
+
+        for i in 0..=bit_len {
+            let z = self.keystream();
+            let auth_bit = self.keystream();
+
+            let m_bit = if i < bit_len {
+                (plaintext[i / 8] >> (i % 8)) & 1
+            } else {
+                1 // appended padding bit m_L
+            };
+
+            if i < bit_len {
+                byte |= (m_bit ^ z) << (i % 8);
+                if i % 8 == 7 || i == bit_len - 1 {
+                    ciphertext[i / 8] = byte;
+                    byte = 0;
+                }
             }
-            ciphertext[i] = plaintext[i] ^ k;
+
+            Self::mac_step(&mut a, &mut r, auth_bit, m_bit);
         }
+
+        *tag = a.to_le_bytes();
     }
 
-    pub fn decrypt_bytes(&mut self, ciphertext: &[u8], plaintext: &mut [u8]) {
-        for i in 0..ciphertext.len() {
-            let mut k = 0;
+    /// Decrypts `ciphertext` and verifies it against `tag`, returning the
+    /// plaintext only if the recomputed Grain-128a MAC matches in constant time.
+    /// Requires an instance created with [`Grain128::keysetup_authenticated`].
+    pub fn decrypt_verify(
+        &mut self,
+        ciphertext: &[u8],
+        tag: &[u8; TAG_LEN],
+    ) -> Result<Vec<u8>, TagMismatch> {
+        assert!(
+            self.authenticate,
+            "decrypt_verify requires Grain128::keysetup_authenticated"
+        );
 
-            for j in 0..8 {
-                k |= self.keystream() << j;
+        let (mut a, mut r) = self.mac_init();
+        let bit_len = ciphertext.len() * 8;
+        let mut plaintext = vec![0u8; ciphertext.len()];
+        let mut byte = 0u8;
+
+        for i in 0..=bit_len {
+            let z = self.keystream();
+            let auth_bit = self.keystream();
+
+            let m_bit = if i < bit_len {
+                ((ciphertext[i / 8] >> (i % 8)) & 1) ^ z
+            } else {
+                1 // appended padding bit m_L
+            };
+
+            if i < bit_len {
+                byte |= m_bit << (i % 8);
+                if i % 8 == 7 || i == bit_len - 1 {
+                    plaintext[i / 8] = byte;
+                    byte = 0;
+                }
             }
-            plaintext[i] = ciphertext[i] ^ k;
+
+            Self::mac_step(&mut a, &mut r, auth_bit, m_bit);
+        }
+
+        if constant_time_eq(&a.to_le_bytes(), tag) {
+            Ok(plaintext)
+        } else {
+            Err(TagMismatch)
+        }
+    }
+}
+
+impl Drop for Grain128 {
+    /// Clears the key and register state so it doesn't linger in freed memory.
+    /// With the `zeroize` feature this uses [`zeroize::Zeroize`], which is
+    /// guaranteed not to be optimized away; without it, a plain reassignment
+    /// is a best effort.
+    fn drop(&mut self) {
+        #[cfg(feature = "zeroize")]
+        {
+            use zeroize::Zeroize;
+            self.key.zeroize();
+            self.LFSR.zeroize();
+            self.NFSR.zeroize();
+        }
+        #[cfg(not(feature = "zeroize"))]
+        {
+            self.key = [0u8; 16];
+            self.LFSR = 0;
+            self.NFSR = 0;
         }
     }
 }
@@ -230,4 +605,118 @@ mod tests {
             assert_eq!(ciphertext.as_ref(), expect);
         }
     }
+
+    #[test]
+    fn authenticated_roundtrip() {
+        let key = hex!("0123456789abcdef123456789abcdef0");
+        let iv = hex!("0123456789abcdef12345678");
+        let plaintext = b"grain-128a test message";
+
+        let mut enc = crate::Grain128::keysetup_authenticated(key.as_ref(), 128, 96);
+        enc.ivsetup(iv.as_ref());
+
+        let mut ciphertext = vec![0u8; plaintext.len()];
+        let mut tag = [0u8; crate::TAG_LEN];
+        enc.encrypt_with_tag(plaintext, &mut ciphertext, &mut tag);
+
+        let mut dec = crate::Grain128::keysetup_authenticated(key.as_ref(), 128, 96);
+        dec.ivsetup(iv.as_ref());
+
+        let recovered = dec.decrypt_verify(&ciphertext, &tag).unwrap();
+        assert_eq!(recovered, plaintext);
+    }
+
+    #[test]
+    fn authenticated_tampered_tag_is_rejected() {
+        let key = hex!("0123456789abcdef123456789abcdef0");
+        let iv = hex!("0123456789abcdef12345678");
+        let plaintext = b"grain-128a test message";
+
+        let mut enc = crate::Grain128::keysetup_authenticated(key.as_ref(), 128, 96);
+        enc.ivsetup(iv.as_ref());
+
+        let mut ciphertext = vec![0u8; plaintext.len()];
+        let mut tag = [0u8; crate::TAG_LEN];
+        enc.encrypt_with_tag(plaintext, &mut ciphertext, &mut tag);
+        tag[0] ^= 1;
+
+        let mut dec = crate::Grain128::keysetup_authenticated(key.as_ref(), 128, 96);
+        dec.ivsetup(iv.as_ref());
+
+        assert_eq!(
+            dec.decrypt_verify(&ciphertext, &tag),
+            Err(crate::TagMismatch)
+        );
+    }
+
+    #[test]
+    fn encrypt_decrypt_roundtrip() {
+        let key = hex!("0123456789abcdef123456789abcdef0");
+        let iv = hex!("0123456789abcdef12345678");
+        let plaintext = b"grain128 one-shot api test";
+
+        let ciphertext = crate::encrypt(&key, &iv, plaintext).unwrap();
+        let recovered = crate::decrypt(&key, &iv, &ciphertext).unwrap();
+        assert_eq!(recovered, plaintext);
+    }
+
+    #[test]
+    fn encrypt_rejects_bad_lengths() {
+        let key = hex!("0123456789abcdef123456789abcdef0");
+        let short_key = &key[..15];
+        let iv = hex!("0123456789abcdef12345678");
+        let bad_iv = &iv[..11];
+
+        assert_eq!(
+            crate::encrypt(short_key, &iv, b"data"),
+            Err(crate::Error::InvalidKeyLength)
+        );
+        assert_eq!(
+            crate::encrypt(&key, bad_iv, b"data"),
+            Err(crate::Error::InvalidIvLength)
+        );
+    }
+
+    #[test]
+    fn try_encrypt_bytes_rejects_mismatched_buffers() {
+        let key = hex!("0123456789abcdef123456789abcdef0");
+        let iv = hex!("0123456789abcdef12345678");
+
+        let mut g = crate::Grain128::keysetup(&key, 128, 96);
+        g.ivsetup(&iv);
+
+        let mut ciphertext = [0u8; 3];
+        assert_eq!(
+            g.try_encrypt_bytes(b"four", &mut ciphertext),
+            Err(crate::Error::BufferSizeMismatch)
+        );
+    }
+
+    #[test]
+    fn io_adapters_roundtrip() {
+        use crate::io::{Grain128Reader, Grain128Writer};
+        use std::io::{Read, Write};
+
+        let key = hex!("0123456789abcdef123456789abcdef0");
+        let iv = hex!("0123456789abcdef12345678");
+        let plaintext = b"grain128 streaming io adapters test message, long enough to span multiple writes";
+
+        let mut enc_core = crate::Grain128::keysetup(&key, 128, 96);
+        enc_core.ivsetup(&iv);
+        let mut ciphertext = Vec::new();
+        let mut writer = Grain128Writer::new(&mut ciphertext, enc_core);
+        // split across several small, unevenly-sized writes
+        for chunk in plaintext.chunks(7) {
+            writer.write_all(chunk).unwrap();
+        }
+        writer.flush().unwrap();
+
+        let mut dec_core = crate::Grain128::keysetup(&key, 128, 96);
+        dec_core.ivsetup(&iv);
+        let mut reader = Grain128Reader::new(ciphertext.as_slice(), dec_core);
+        let mut recovered = Vec::new();
+        reader.read_to_end(&mut recovered).unwrap();
+
+        assert_eq!(recovered, plaintext);
+    }
 }
\ No newline at end of file
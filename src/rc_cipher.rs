@@ -0,0 +1,80 @@
+//! RustCrypto `cipher` crate trait implementations, enabled with the `cipher`
+//! feature. These let [`Grain128Cipher`] plug into generic code written
+//! against `StreamCipher`, the same way `ChaCha20` or `ctr::Ctr128BE<Aes128>`
+//! do, instead of callers going through the crate's own bespoke API.
+
+use cipher::{
+    consts::{U12, U16},
+    Iv, IvSizeUser, Key, KeyIvInit, KeySizeUser, StreamCipher, StreamCipherError,
+    StreamCipherSeek,
+};
+
+use crate::Grain128;
+
+/// IV length in bits expected by [`Grain128Cipher`] (the standard 96-bit
+/// Grain-128 IV).
+const IV_SIZE_BITS: usize = 96;
+
+/// Adapter that exposes the bit-serial [`Grain128`] core through the
+/// RustCrypto `cipher` crate traits (`KeyIvInit`, `StreamCipher`,
+/// `StreamCipherSeek`).
+pub struct Grain128Cipher {
+    core: Grain128,
+    /// Logical byte position into the keystream, tracked so `StreamCipherSeek`
+    /// can fast-forward (or reject rewinding) a bit-serial keystream that has
+    /// no cheaper way to jump ahead than regenerating and discarding bytes.
+    position: u64,
+}
+
+impl KeySizeUser for Grain128Cipher {
+    type KeySize = U16;
+}
+
+impl IvSizeUser for Grain128Cipher {
+    type IvSize = U12;
+}
+
+impl KeyIvInit for Grain128Cipher {
+    fn new(key: &Key<Self>, iv: &Iv<Self>) -> Self {
+        let mut core = Grain128::keysetup(key.as_slice(), 128, IV_SIZE_BITS);
+        core.ivsetup(iv.as_slice());
+        Self { core, position: 0 }
+    }
+}
+
+impl StreamCipher for Grain128Cipher {
+    fn try_apply_keystream_inout(
+        &mut self,
+        mut buf: cipher::inout::InOutBuf<'_, '_, u8>,
+    ) -> Result<(), StreamCipherError> {
+        for i in 0..buf.len() {
+            let mut block = buf.get(i);
+            let keystream_byte = self.core.keystream_byte();
+            *block.get_out() = *block.get_in() ^ keystream_byte;
+        }
+        self.position += buf.len() as u64;
+        Ok(())
+    }
+}
+
+impl StreamCipherSeek for Grain128Cipher {
+    fn try_current_pos<T: cipher::SeekNum>(&self) -> Result<T, cipher::OverflowError> {
+        T::from_block_byte(self.position, 0, 1)
+    }
+
+    fn try_seek<T: cipher::SeekNum>(&mut self, pos: T) -> Result<(), StreamCipherError> {
+        let (target, _): (u64, u8) = pos.into_block_byte(1).map_err(|_| StreamCipherError)?;
+        if target < self.position {
+            // The bit-serial core only ever advances forward; rewinding would
+            // require re-running keysetup/ivsetup, which isn't cheap to do
+            // implicitly from a seek call.
+            return Err(StreamCipherError);
+        }
+
+        for _ in self.position..target {
+            let _ = self.core.keystream_byte();
+        }
+        self.position = target;
+        Ok(())
+    }
+}